@@ -1,3 +1,5 @@
+use glam::DVec2;
+
 /// A collection of particles and emitters
 pub trait ParticleSystem {
     /// The type of particle that this system will contain
@@ -45,6 +47,14 @@ pub trait ParticleSystem {
             .collect()
     }
 
+    /// Applies the system's force fields to its particles, integrating their velocities
+    ///
+    /// The default implementation does nothing, so systems whose particles do not
+    /// implement [`Dynamics`] simply opt out. Field-aware systems such as
+    /// [`DynamicParticleSystem`] override this to push particles around before
+    /// their positions are integrated in [`ParticleSystem::update_particles`].
+    fn update_fields(&mut self, _dt: f64) {}
+
     /// Removes dead particles from the system
     fn clean_particles(&mut self);
 
@@ -53,10 +63,11 @@ pub trait ParticleSystem {
 
     /// Updates the particle system
     ///
-    /// This method is comprised of 3 steps:
+    /// This method is comprised of 4 steps:
     /// 1. Update emitters and add the new particles to the system
-    /// 2. Update all particles in the system
-    /// 3. Remove dead particles and emitters from the system
+    /// 2. Apply force fields to the particles
+    /// 3. Update all particles in the system
+    /// 4. Remove dead particles and emitters from the system
     fn update(&mut self, dt: f64) {
         let new_particles = self.update_emitters(dt);
 
@@ -64,18 +75,54 @@ pub trait ParticleSystem {
             self.add_particle(new_particle);
         }
 
+        self.update_fields(dt);
         self.update_particles(dt);
 
         self.clean_particles();
         self.clean_emitters();
     }
 
-    /// Draws all particles in the system
+    /// Draws all particles in the system in arbitrary storage order
+    ///
+    /// This is the right choice for opaque particles, where draw order does not
+    /// matter and sorting would be wasted work. Translucent particles that
+    /// overlap should be drawn with [`ParticleSystem::draw_sorted`] instead.
     fn draw(&self) {
         for particle in self.iter_particles() {
             particle.draw();
         }
     }
+
+    /// Draws all particles sorted by their [`Sortable::sort_key`], largest first
+    ///
+    /// Translucent particles must be drawn back-to-front for alpha blending to
+    /// compose correctly; drawing the oldest (or farthest) particles first gives
+    /// the right result when `sort_key` increases with age or camera distance.
+    fn draw_sorted(&self)
+    where
+        Self::ParticleType: Sortable,
+    {
+        self.draw_sorted_by(Sortable::sort_key);
+    }
+
+    /// Draws all particles sorted by `key_fn`, largest key first
+    ///
+    /// This lets callers sort by something the particle itself does not know,
+    /// such as the distance to a view point, without implementing [`Sortable`].
+    fn draw_sorted_by(&self, key_fn: impl Fn(&Self::ParticleType) -> f64) {
+        let mut particles: Vec<&Self::ParticleType> =
+            self.iter_particles().map(|particle| particle.as_ref()).collect();
+
+        particles.sort_by(|a, b| {
+            key_fn(b)
+                .partial_cmp(&key_fn(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        for particle in particles {
+            particle.draw();
+        }
+    }
 }
 
 /// A base particle system using vectors to store the particles and emitters
@@ -135,6 +182,333 @@ impl<P> ParticleSystem for BaseParticleSystem<P> {
     }
 }
 
+/// Decides what a [`PooledParticleSystem`] does when a particle is added while at capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Drop the incoming particle and leave the pool untouched
+    #[default]
+    Drop,
+
+    /// Overwrite the oldest living particle with the incoming one
+    Overwrite,
+}
+
+/// A single slot in a [`PooledParticleSystem`]'s backing storage
+///
+/// A dead slot keeps its index in the free list rather than being shifted out of
+/// the backing vector, so adding a particle reuses the slot without growing or
+/// compacting `slots`.
+struct Slot<P: ?Sized> {
+    particle: Option<Box<P>>,
+    alive: bool,
+}
+
+/// A particle system backed by an object pool with a fixed capacity
+///
+/// Unlike [`BaseParticleSystem`], which `retain`s and shifts the backing vector
+/// each frame, this system keeps dead slots around and recycles their indices
+/// through a free list. Once the pool has warmed up to `max_count` living
+/// particles the `slots` and `order` storage stops growing and `slots` is never
+/// shifted (only the `order` queue of living indices is compacted in
+/// `clean_particles`), which bounds memory and avoids per-frame churn for high-emission
+/// workloads like explosions and fountains. (Each spawned particle is still
+/// `Box`-allocated by its emitter; only the pool's own storage is stable.)
+pub struct PooledParticleSystem<P> {
+    slots: Vec<Slot<dyn Particle<Position = P>>>,
+    free: Vec<usize>,
+    order: std::collections::VecDeque<usize>,
+    emitters: Vec<Box<dyn ParticleEmitter<ParticleType = dyn Particle<Position = P>>>>,
+    max_count: usize,
+    overflow_policy: OverflowPolicy,
+}
+
+impl<P> PooledParticleSystem<P> {
+    /// Creates a new pool that holds at most `max_count` living particles
+    ///
+    /// Incoming particles are dropped once the pool is full; use
+    /// [`PooledParticleSystem::with_overflow_policy`] to overwrite instead.
+    pub fn new(max_count: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(max_count),
+            free: Vec::new(),
+            order: std::collections::VecDeque::new(),
+            emitters: Vec::new(),
+            max_count,
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+
+    /// Creates a new pool with an explicit [`OverflowPolicy`]
+    pub fn with_overflow_policy(max_count: usize, overflow_policy: OverflowPolicy) -> Self {
+        Self {
+            overflow_policy,
+            ..Self::new(max_count)
+        }
+    }
+
+    /// Returns the maximum number of living particles the pool can hold
+    pub fn max_count(&self) -> usize {
+        self.max_count
+    }
+
+    /// Places a particle into the slot at `index`, marking it alive and recording its order
+    fn occupy(&mut self, index: usize, particle: Box<dyn Particle<Position = P>>) {
+        self.slots[index].particle = Some(particle);
+        self.slots[index].alive = true;
+        self.order.push_back(index);
+    }
+}
+
+impl<P> ParticleSystem for PooledParticleSystem<P> {
+    /// This system can hold any particle that implements `Particle` with the same `Position` type
+    type ParticleType = dyn Particle<Position = P>;
+
+    /// This system can hold any emitter that emits any particle that implements `Particle` with the same `Position` type
+    type EmitterType = dyn ParticleEmitter<ParticleType = Self::ParticleType>;
+
+    fn iter_particles(
+        &self,
+    ) -> impl Iterator<Item = &Box<Self::ParticleType>> {
+        self.slots
+            .iter()
+            .filter(|slot| slot.alive)
+            .filter_map(|slot| slot.particle.as_ref())
+    }
+
+    fn iter_particles_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Box<Self::ParticleType>> {
+        self.slots
+            .iter_mut()
+            .filter(|slot| slot.alive)
+            .filter_map(|slot| slot.particle.as_mut())
+    }
+
+    fn iter_emitters(
+        &self,
+    ) -> impl Iterator<Item = &Box<Self::EmitterType>> {
+        self.emitters.iter()
+    }
+
+    fn iter_emitters_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Box<Self::EmitterType>> {
+        self.emitters.iter_mut()
+    }
+
+    fn add_particle(&mut self, particle: Box<Self::ParticleType>) {
+        if let Some(index) = self.free.pop() {
+            self.occupy(index, particle);
+        } else if self.slots.len() < self.max_count {
+            let index = self.slots.len();
+            self.slots.push(Slot {
+                particle: Some(particle),
+                alive: true,
+            });
+            self.order.push_back(index);
+        } else {
+            match self.overflow_policy {
+                OverflowPolicy::Drop => {}
+                OverflowPolicy::Overwrite => {
+                    if let Some(index) = self.order.pop_front() {
+                        self.occupy(index, particle);
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_emitter(&mut self, emitter: Box<Self::EmitterType>) {
+        self.emitters.push(emitter);
+    }
+
+    fn clean_particles(&mut self) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.alive && !slot.particle.as_ref().is_some_and(|particle| particle.is_alive()) {
+                slot.alive = false;
+                self.free.push(index);
+            }
+        }
+
+        self.order.retain(|index| self.slots[*index].alive);
+    }
+
+    fn clean_emitters(&mut self) {
+        self.emitters.retain(|emitter| emitter.is_alive());
+    }
+}
+
+/// A particle system that applies force fields to its particles each frame
+///
+/// Unlike [`BaseParticleSystem`], which stores type-erased [`Particle`] trait
+/// objects, this system is generic over a concrete particle type that also
+/// implements [`Dynamics`]. That lets [`ParticleSystem::update`] integrate the
+/// accelerations contributed by the system's [`Field`]s into every particle's
+/// velocity before their positions are updated.
+pub struct DynamicParticleSystem<T: Particle> {
+    particles: Vec<Box<T>>,
+    emitters: Vec<Box<dyn ParticleEmitter<ParticleType = T>>>,
+    fields: Vec<Box<dyn Field<Position = <T as Particle>::Position>>>,
+}
+
+impl<T: Particle> Default for DynamicParticleSystem<T> {
+    fn default() -> Self {
+        Self {
+            particles: Vec::new(),
+            emitters: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl<T: Particle> DynamicParticleSystem<T> {
+    /// Adds a force field to the system
+    pub fn add_field(&mut self, field: Box<dyn Field<Position = <T as Particle>::Position>>) {
+        self.fields.push(field);
+    }
+}
+
+impl<T> ParticleSystem for DynamicParticleSystem<T>
+where
+    T: Particle + Dynamics<Position = <T as Particle>::Position>,
+    <T as Particle>::Position:
+        Copy + Default + std::ops::Add<Output = <T as Particle>::Position> + std::ops::Mul<f64, Output = <T as Particle>::Position>,
+{
+    /// This system holds a single concrete particle type that is both [`Particle`] and [`Dynamics`]
+    type ParticleType = T;
+
+    /// This system can hold any emitter that emits its particle type
+    type EmitterType = dyn ParticleEmitter<ParticleType = T>;
+
+    fn iter_particles(
+        &self,
+    ) -> impl Iterator<Item = &Box<Self::ParticleType>> {
+        self.particles.iter()
+    }
+
+    fn iter_particles_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Box<Self::ParticleType>> {
+        self.particles.iter_mut()
+    }
+
+    fn iter_emitters(
+        &self,
+    ) -> impl Iterator<Item = &Box<Self::EmitterType>> {
+        self.emitters.iter()
+    }
+
+    fn iter_emitters_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Box<Self::EmitterType>> {
+        self.emitters.iter_mut()
+    }
+
+    fn add_particle(&mut self, particle: Box<Self::ParticleType>) {
+        self.particles.push(particle);
+    }
+
+    fn add_emitter(&mut self, emitter: Box<Self::EmitterType>) {
+        self.emitters.push(emitter);
+    }
+
+    fn update_fields(&mut self, dt: f64) {
+        for particle in self.particles.iter_mut() {
+            let position = particle.position();
+            let velocity = particle.velocity();
+
+            let acceleration = self
+                .fields
+                .iter()
+                .fold(<T as Particle>::Position::default(), |acceleration, field| {
+                    acceleration + field.force(position, velocity)
+                });
+
+            particle.set_velocity(velocity + acceleration * dt);
+        }
+    }
+
+    fn clean_particles(&mut self) {
+        self.particles.retain(|particle| particle.is_alive());
+    }
+
+    fn clean_emitters(&mut self) {
+        self.emitters.retain(|emitter| emitter.is_alive());
+    }
+}
+
+/// A uniform acceleration applied to every particle, such as gravity
+pub struct Gravity(pub DVec2);
+
+impl Field for Gravity {
+    type Position = DVec2;
+
+    fn force(&self, _position: DVec2, _velocity: DVec2) -> DVec2 {
+        self.0
+    }
+}
+
+/// A field that pushes particles toward or away from a point
+///
+/// With a positive `strength` particles are repelled from `center` (an
+/// explosion); a negative `strength` attracts them. When `inverse_square` is set
+/// the force falls off with the square of the distance, like real gravity.
+pub struct Radial {
+    /// The point the force radiates from
+    pub center: DVec2,
+    /// The magnitude of the force; positive repels, negative attracts
+    pub strength: f64,
+    /// Whether the force falls off with the square of the distance
+    pub inverse_square: bool,
+}
+
+impl Field for Radial {
+    type Position = DVec2;
+
+    fn force(&self, position: DVec2, _velocity: DVec2) -> DVec2 {
+        let offset = position - self.center;
+        let direction = offset.normalize_or_zero();
+
+        if self.inverse_square {
+            let distance_squared = offset.length_squared().max(f64::EPSILON);
+            direction * (self.strength / distance_squared)
+        } else {
+            direction * self.strength
+        }
+    }
+}
+
+/// A field that swirls particles around a point, perpendicular to the radial direction
+pub struct Vortex {
+    /// The point the particles swirl around
+    pub center: DVec2,
+    /// The magnitude of the swirl; the sign selects the direction of rotation
+    pub strength: f64,
+}
+
+impl Field for Vortex {
+    type Position = DVec2;
+
+    fn force(&self, position: DVec2, _velocity: DVec2) -> DVec2 {
+        let direction = (position - self.center).normalize_or_zero();
+        direction.perp() * self.strength
+    }
+}
+
+/// A field that opposes motion, scaling with velocity, like air resistance
+pub struct Drag {
+    /// The drag coefficient; larger values slow particles down faster
+    pub coefficient: f64,
+}
+
+impl Field for Drag {
+    type Position = DVec2;
+
+    fn force(&self, _position: DVec2, velocity: DVec2) -> DVec2 {
+        velocity * -self.coefficient
+    }
+}
+
 /// Creates new particles
 pub trait ParticleEmitter {
     /// The type of the particles to be emitted
@@ -147,6 +521,151 @@ pub trait ParticleEmitter {
     fn is_alive(&self) -> bool;
 }
 
+/// An emitter that spawns particles at a steady rate over time
+///
+/// Fractional particles are accumulated between frames so that low rates still
+/// emit on the right average cadence. An optional `max_emissions` caps the total
+/// number of particles spawned, after which the emitter reports itself dead.
+pub struct RateEmitter<P> {
+    rate: f64,
+    accumulator: f64,
+    max_emissions: Option<usize>,
+    emitted: usize,
+    spawn_fn: Box<dyn FnMut() -> Box<dyn Particle<Position = P>>>,
+}
+
+impl<P> RateEmitter<P> {
+    /// Creates a new emitter that spawns `rate` particles per second from `spawn_fn`
+    pub fn new(
+        rate: f64,
+        spawn_fn: Box<dyn FnMut() -> Box<dyn Particle<Position = P>>>,
+    ) -> Self {
+        Self {
+            rate,
+            accumulator: 0.0,
+            max_emissions: None,
+            emitted: 0,
+            spawn_fn,
+        }
+    }
+
+    /// Caps the emitter at `max_emissions` total particles before it dies
+    pub fn with_max_emissions(mut self, max_emissions: usize) -> Self {
+        self.max_emissions = Some(max_emissions);
+        self
+    }
+}
+
+impl<P> ParticleEmitter for RateEmitter<P> {
+    type ParticleType = dyn Particle<Position = P>;
+
+    fn update(&mut self, dt: f64) -> Vec<Box<Self::ParticleType>> {
+        self.accumulator += self.rate * dt;
+
+        let mut count = self.accumulator.floor() as usize;
+        self.accumulator -= count as f64;
+
+        if let Some(max_emissions) = self.max_emissions {
+            count = count.min(max_emissions - self.emitted);
+        }
+
+        self.emitted += count;
+
+        (0..count).map(|_| (self.spawn_fn)()).collect()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.max_emissions
+            .is_none_or(|max_emissions| self.emitted < max_emissions)
+    }
+}
+
+/// An emitter that spawns a fixed number of particles at once
+///
+/// With no interval the burst fires a single time and then dies. Given a
+/// repeating interval it fires the same count every time the interval elapses and
+/// stays alive, which is handy for rhythmic effects like a pulsing fountain.
+pub struct BurstEmitter<P> {
+    count: usize,
+    interval: Option<f64>,
+    timer: f64,
+    fired: bool,
+    spawn_fn: Box<dyn FnMut() -> Box<dyn Particle<Position = P>>>,
+}
+
+impl<P> BurstEmitter<P> {
+    /// Creates a one-shot burst that fires `count` particles on its first update, then dies
+    pub fn new(
+        count: usize,
+        spawn_fn: Box<dyn FnMut() -> Box<dyn Particle<Position = P>>>,
+    ) -> Self {
+        Self {
+            count,
+            interval: None,
+            timer: 0.0,
+            fired: false,
+            spawn_fn,
+        }
+    }
+
+    /// Creates a burst that fires `count` particles every `interval` seconds and never dies
+    ///
+    /// `interval` must be greater than zero; a non-positive interval would make
+    /// the timer loop in [`BurstEmitter::update`] never terminate.
+    pub fn repeating(
+        count: usize,
+        interval: f64,
+        spawn_fn: Box<dyn FnMut() -> Box<dyn Particle<Position = P>>>,
+    ) -> Self {
+        assert!(interval > 0.0, "repeating burst interval must be positive");
+
+        Self {
+            count,
+            interval: Some(interval),
+            timer: 0.0,
+            fired: false,
+            spawn_fn,
+        }
+    }
+
+    /// Spawns `self.count` particles from the spawn function
+    fn burst(&mut self) -> Vec<Box<dyn Particle<Position = P>>> {
+        (0..self.count).map(|_| (self.spawn_fn)()).collect()
+    }
+}
+
+impl<P> ParticleEmitter for BurstEmitter<P> {
+    type ParticleType = dyn Particle<Position = P>;
+
+    fn update(&mut self, dt: f64) -> Vec<Box<Self::ParticleType>> {
+        match self.interval {
+            None => {
+                if self.fired {
+                    Vec::new()
+                } else {
+                    self.fired = true;
+                    self.burst()
+                }
+            }
+            Some(interval) => {
+                self.timer += dt;
+
+                let mut particles = Vec::new();
+                while self.timer >= interval {
+                    self.timer -= interval;
+                    particles.extend(self.burst());
+                }
+
+                particles
+            }
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        self.interval.is_some() || !self.fired
+    }
+}
+
 /// A representation of some particle in 2-dimensional space
 pub trait Particle {
     /// The position type of the particle
@@ -165,6 +684,50 @@ pub trait Particle {
     fn is_alive(&self) -> bool;
 }
 
+/// A particle whose motion can be influenced by force fields
+///
+/// [`Particle`] only exposes a read-only position, which is enough for purely
+/// kinematic effects. Particles that want to be pushed around by a [`Field`]
+/// additionally expose their velocity so the system can integrate accelerations
+/// into it each frame.
+pub trait Dynamics {
+    /// The position and velocity type of the particle
+    type Position;
+
+    /// The position of the particle in space
+    fn position(&self) -> Self::Position;
+
+    /// The current velocity of the particle
+    fn velocity(&self) -> Self::Position;
+
+    /// Sets the velocity of the particle
+    fn set_velocity(&mut self, velocity: Self::Position);
+}
+
+/// A global acceleration source applied to the particles of a [`DynamicParticleSystem`]
+///
+/// Given a particle's position and velocity, a field returns the acceleration it
+/// contributes. The system sums the contributions of all its fields and integrates
+/// the result into each particle's velocity.
+pub trait Field {
+    /// The position and velocity type the field operates on
+    type Position;
+
+    /// Returns the acceleration this field applies to a particle at `position` moving at `velocity`
+    fn force(&self, position: Self::Position, velocity: Self::Position) -> Self::Position;
+}
+
+/// A particle that can be ordered for back-to-front drawing
+///
+/// The key is drawn from largest to smallest by [`ParticleSystem::draw_sorted`],
+/// so a key that grows with age or camera distance puts the oldest or farthest
+/// particles first, which is what correct alpha blending requires. [`Aging`]
+/// particles can simply return [`Aging::get_age`].
+pub trait Sortable {
+    /// Returns the key this particle is sorted by when drawn
+    fn sort_key(&self) -> f64;
+}
+
 /// Tracks age in a particle
 pub trait Aging {
     /// Gets the current age of the particle
@@ -189,3 +752,357 @@ pub trait MaxAging: Aging {
         self.get_age_percent() < 1.0
     }
 }
+
+/// A value that can be linearly interpolated toward another value of the same type
+///
+/// `t` is a normalized fraction, typically a particle's lifetime percentage from
+/// [`MaxAging::get_age_percent`]. `t == 0.0` yields `self` and `t == 1.0` yields
+/// `other`; values outside that range extrapolate.
+pub trait Lerp {
+    /// Interpolates from `self` toward `other` by the fraction `t`
+    fn lerp(self, other: Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t as f32
+    }
+}
+
+impl Lerp for DVec2 {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+}
+
+/// A straight RGBA color, each channel in the usual `0.0..=1.0` range
+impl Lerp for (f32, f32, f32, f32) {
+    fn lerp(self, other: Self, t: f64) -> Self {
+        (
+            self.0.lerp(other.0, t),
+            self.1.lerp(other.1, t),
+            self.2.lerp(other.2, t),
+            self.3.lerp(other.3, t),
+        )
+    }
+}
+
+/// An easing function used to shape an interpolation curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// No shaping; the fraction is used as-is
+    #[default]
+    Linear,
+
+    /// Starts slow and accelerates
+    EaseIn,
+
+    /// Starts fast and decelerates
+    EaseOut,
+
+    /// Accelerates out of the start and decelerates into the end
+    SmoothStep,
+}
+
+impl Easing {
+    /// Maps a normalized fraction through the easing curve
+    pub fn apply(&self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::SmoothStep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+/// Interpolation helpers driven by a particle's lifetime fraction
+///
+/// This is blanket-implemented for every [`MaxAging`] particle, so a particle's
+/// `draw` can fade and shrink over its lifetime by lerping between a start and
+/// end value, for example `self.lerp(start_color, end_color)` and
+/// `self.lerp(start_radius, end_radius)`.
+pub trait Interpolated: MaxAging {
+    /// Interpolates between `start` and `end` by the particle's age percent
+    fn lerp<T: Lerp>(&self, start: T, end: T) -> T {
+        start.lerp(end, self.get_age_percent())
+    }
+
+    /// Like [`Interpolated::lerp`] but clamps the age percent to `0.0..=1.0` first
+    fn lerp_clamped<T: Lerp>(&self, start: T, end: T) -> T {
+        start.lerp(end, self.get_age_percent().clamp(0.0, 1.0))
+    }
+
+    /// Interpolates between `start` and `end`, shaping the clamped age percent with `easing`
+    fn lerp_eased<T: Lerp>(&self, start: T, end: T, easing: Easing) -> T {
+        start.lerp(end, easing.apply(self.get_age_percent().clamp(0.0, 1.0)))
+    }
+}
+
+impl<M: MaxAging + ?Sized> Interpolated for M {}
+
+#[cfg(feature = "serde")]
+pub use presets::*;
+
+/// Serde-driven emitter presets for data-driven, hot-reloadable effects
+///
+/// A designer can describe the spawn envelope of an effect in a RON or JSON file
+/// and load it at runtime, mirroring the way orxonox loads particle systems from
+/// XML. Because the crate is backend-agnostic, the preset only describes *what*
+/// to spawn; the caller supplies a closure that turns a sampled [`ParticleSpawn`]
+/// into their own concrete [`Particle`].
+#[cfg(feature = "serde")]
+mod presets {
+    use super::*;
+    use rand::random_range;
+    use serde::{Deserialize, Serialize};
+
+    /// The concrete values sampled from an [`EmitterPreset`] for a single particle
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct ParticleSpawn {
+        /// The starting position of the particle
+        pub position: DVec2,
+        /// The initial velocity of the particle
+        pub velocity: DVec2,
+        /// The lifetime of the particle in seconds
+        pub max_age: f64,
+        /// The color at the start of the particle's life
+        pub color_start: (f32, f32, f32, f32),
+        /// The color at the end of the particle's life
+        pub color_end: (f32, f32, f32, f32),
+        /// The size at the start of the particle's life
+        pub size_start: f64,
+        /// The size at the end of the particle's life
+        pub size_end: f64,
+    }
+
+    /// A serializable description of an effect's spawn envelope
+    ///
+    /// Position and velocity are sampled uniformly between their min and max
+    /// vectors per particle; the color and size endpoints feed the interpolation
+    /// helpers so particles can fade and shrink over their lifetime.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct EmitterPreset {
+        /// The minimum spawn position
+        pub position_min: DVec2,
+        /// The maximum spawn position
+        pub position_max: DVec2,
+        /// The minimum initial velocity
+        pub velocity_min: DVec2,
+        /// The maximum initial velocity
+        pub velocity_max: DVec2,
+        /// The minimum lifetime in seconds
+        pub lifetime_min: f64,
+        /// The maximum lifetime in seconds
+        pub lifetime_max: f64,
+        /// The color at the start of a particle's life
+        pub color_start: (f32, f32, f32, f32),
+        /// The color at the end of a particle's life
+        pub color_end: (f32, f32, f32, f32),
+        /// The size at the start of a particle's life
+        pub size_start: f64,
+        /// The size at the end of a particle's life
+        pub size_end: f64,
+        /// The emission rate in particles per second, used by [`from_preset`]
+        pub rate: f64,
+        /// The number of particles a single burst emits, used by [`burst_from_preset`]
+        pub burst_count: usize,
+    }
+
+    impl EmitterPreset {
+        /// Samples the preset's ranges to produce the values for a single particle
+        ///
+        /// Each range is normalized so the smaller bound is the lower end, which
+        /// keeps a transposed `min`/`max` in a designer-authored preset from
+        /// producing an empty range and panicking.
+        pub fn sample(&self) -> ParticleSpawn {
+            ParticleSpawn {
+                position: DVec2::new(
+                    sample_range(self.position_min.x, self.position_max.x),
+                    sample_range(self.position_min.y, self.position_max.y),
+                ),
+                velocity: DVec2::new(
+                    sample_range(self.velocity_min.x, self.velocity_max.x),
+                    sample_range(self.velocity_min.y, self.velocity_max.y),
+                ),
+                max_age: sample_range(self.lifetime_min, self.lifetime_max),
+                color_start: self.color_start,
+                color_end: self.color_end,
+                size_start: self.size_start,
+                size_end: self.size_end,
+            }
+        }
+    }
+
+    /// Samples a uniform value between `a` and `b`, regardless of which bound is larger
+    fn sample_range(a: f64, b: f64) -> f64 {
+        random_range(a.min(b)..=a.max(b))
+    }
+
+    /// Builds a [`RateEmitter`] that samples `preset` for every particle it spawns
+    ///
+    /// `build` turns each sampled [`ParticleSpawn`] into a concrete particle, so
+    /// the preset stays independent of any particular rendering backend.
+    pub fn from_preset<P, F>(preset: &EmitterPreset, mut build: F) -> RateEmitter<P>
+    where
+        F: FnMut(ParticleSpawn) -> Box<dyn Particle<Position = P>> + 'static,
+    {
+        let preset = preset.clone();
+
+        RateEmitter::new(
+            preset.rate,
+            Box::new(move || build(preset.sample())),
+        )
+    }
+
+    /// Builds a one-shot [`BurstEmitter`] that fires `preset.burst_count` sampled particles
+    pub fn burst_from_preset<P, F>(preset: &EmitterPreset, mut build: F) -> BurstEmitter<P>
+    where
+        F: FnMut(ParticleSpawn) -> Box<dyn Particle<Position = P>> + 'static,
+    {
+        let preset = preset.clone();
+
+        BurstEmitter::new(
+            preset.burst_count,
+            Box::new(move || build(preset.sample())),
+        )
+    }
+
+    /// Registers a rate emitter for every preset into `system` in one call
+    ///
+    /// `build` is shared across every preset's spawn function, so it must be
+    /// cloneable; each preset samples its own ranges independently.
+    pub fn register_presets<P, F>(
+        system: &mut BaseParticleSystem<P>,
+        presets: &[EmitterPreset],
+        build: F,
+    ) where
+        P: 'static,
+        F: FnMut(ParticleSpawn) -> Box<dyn Particle<Position = P>> + Clone + 'static,
+    {
+        for preset in presets {
+            system.add_emitter(Box::new(from_preset(preset, build.clone())));
+        }
+    }
+
+    /// Loads a list of presets from a JSON string
+    pub fn load_presets_json(source: &str) -> serde_json::Result<Vec<EmitterPreset>> {
+        serde_json::from_str(source)
+    }
+
+    /// Loads a list of presets from a RON string
+    pub fn load_presets_ron(source: &str) -> Result<Vec<EmitterPreset>, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal particle used to drive the systems under test
+    struct TestParticle {
+        position: DVec2,
+        velocity: DVec2,
+        age: f64,
+        max_age: f64,
+    }
+
+    impl TestParticle {
+        fn new(position: DVec2, max_age: f64) -> Self {
+            Self {
+                position,
+                velocity: DVec2::ZERO,
+                age: 0.0,
+                max_age,
+            }
+        }
+    }
+
+    impl Particle for TestParticle {
+        type Position = DVec2;
+
+        fn get_position(&self) -> DVec2 {
+            self.position
+        }
+
+        fn update(&mut self, dt: f64) {
+            self.position += self.velocity * dt;
+            self.age += dt;
+        }
+
+        fn draw(&self) {}
+
+        fn is_alive(&self) -> bool {
+            self.age < self.max_age
+        }
+    }
+
+    fn spawn() -> Box<dyn Particle<Position = DVec2>> {
+        Box::new(TestParticle::new(DVec2::ZERO, 1.0))
+    }
+
+    #[test]
+    fn pool_reuses_freed_slots_without_growing() {
+        let mut system = PooledParticleSystem::<DVec2>::new(3);
+
+        for _ in 0..3 {
+            system.add_particle(Box::new(TestParticle::new(DVec2::ZERO, 0.5)));
+        }
+        assert_eq!(system.slots.len(), 3);
+
+        // Aging past their max age kills every particle and returns its slot
+        system.update(1.0);
+        assert_eq!(system.iter_particles().count(), 0);
+        assert_eq!(system.free.len(), 3);
+
+        // The next batch reuses the free list rather than growing the storage
+        for _ in 0..3 {
+            system.add_particle(Box::new(TestParticle::new(DVec2::ZERO, 0.5)));
+        }
+        assert_eq!(system.slots.len(), 3);
+        assert!(system.free.is_empty());
+        assert_eq!(system.iter_particles().count(), 3);
+    }
+
+    #[test]
+    fn overflow_overwrite_evicts_the_oldest_particle() {
+        let mut system =
+            PooledParticleSystem::<DVec2>::with_overflow_policy(2, OverflowPolicy::Overwrite);
+
+        system.add_particle(Box::new(TestParticle::new(DVec2::new(1.0, 0.0), 100.0)));
+        system.add_particle(Box::new(TestParticle::new(DVec2::new(2.0, 0.0), 100.0)));
+        // Full: this overwrites the oldest particle (x = 1.0)
+        system.add_particle(Box::new(TestParticle::new(DVec2::new(3.0, 0.0), 100.0)));
+
+        let xs: Vec<f64> = system.iter_particles().map(|p| p.get_position().x).collect();
+        assert_eq!(system.iter_particles().count(), 2);
+        assert!(xs.contains(&2.0));
+        assert!(xs.contains(&3.0));
+        assert!(!xs.contains(&1.0));
+    }
+
+    #[test]
+    fn rate_emitter_accumulates_fractional_particles() {
+        let mut emitter = RateEmitter::<DVec2>::new(1.0, Box::new(spawn));
+
+        // At 1 particle/second, half-second steps emit on every other frame
+        let counts: Vec<usize> = (0..4).map(|_| emitter.update(0.5).len()).collect();
+        assert_eq!(counts, vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn rate_emitter_caps_at_max_emissions() {
+        let mut emitter = RateEmitter::<DVec2>::new(100.0, Box::new(spawn)).with_max_emissions(3);
+
+        assert_eq!(emitter.update(1.0).len(), 3);
+        assert!(!emitter.is_alive());
+        assert_eq!(emitter.update(1.0).len(), 0);
+    }
+}